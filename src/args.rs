@@ -1,21 +1,69 @@
+use std::path::PathBuf;
+
 use clap::{Parser, Subcommand};
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 pub struct Cli {
-    /// Optional name to operate on
-    name: Option<String>,
-
     #[command(subcommand)]
-    pub command: Option<Commands>,
+    pub command: Commands,
 }
 
 #[derive(Subcommand)]
 pub enum Commands {
-    /// does testing things
-    Test {
-        /// lists test values
-        #[arg(short, long)]
-        list: bool,
+    /// Hides a secret message inside a new chunk of a PNG file
+    Encode {
+        /// Path to the PNG file to embed the message in
+        path: PathBuf,
+
+        /// Four-letter chunk type to store the message under, e.g. "ruSt"
+        chunk_type: String,
+
+        /// The secret message to embed
+        message: String,
+
+        /// Where to write the resulting PNG (defaults to overwriting `path`)
+        output: Option<PathBuf>,
+
+        /// Base64-encode the message before embedding it
+        #[arg(long)]
+        base64: bool,
+
+        /// Encrypt the message with this passphrase before embedding it
+        /// (AES-256-GCM, keyed by a SHA-256 hash of the passphrase)
+        #[arg(long, conflicts_with = "base64")]
+        passphrase: Option<String>,
+    },
+
+    /// Prints the secret message stored in a chunk of a PNG file
+    Decode {
+        /// Path to the PNG file to read the message from
+        path: PathBuf,
+
+        /// Four-letter chunk type the message is stored under
+        chunk_type: String,
+
+        /// The message was base64-encoded when it was embedded
+        #[arg(long)]
+        base64: bool,
+
+        /// The message was encrypted with this passphrase when it was embedded
+        #[arg(long, conflicts_with = "base64")]
+        passphrase: Option<String>,
+    },
+
+    /// Removes a chunk from a PNG file
+    Remove {
+        /// Path to the PNG file to remove the chunk from
+        path: PathBuf,
+
+        /// Four-letter chunk type of the chunk to remove
+        chunk_type: String,
+    },
+
+    /// Prints every chunk's type and length
+    Print {
+        /// Path to the PNG file to inspect
+        path: PathBuf,
     },
 }
\ No newline at end of file
@@ -0,0 +1,510 @@
+use std::io::{BufReader, Read};
+use super::*;
+
+use crate::chunk_type::ChunkType;
+use crate::payload::PayloadCodec;
+use bytes::{BufMut, Bytes, BytesMut};
+use crc::{Crc, Digest, CRC_32_ISO_HDLC};
+
+const CRC_32: Crc<u32> = Crc::<u32>::new(&CRC_32_ISO_HDLC);
+
+/// A single PNG chunk. `data` is a reference-counted `Bytes` rather than a
+/// `Vec<u8>`, so sharing a chunk's payload across a `Png` container (slicing,
+/// cloning) never copies the underlying bytes.
+pub struct Chunk {
+    data: Bytes,
+    length: u32,
+    chunk_type: ChunkType,
+    crc: u32,
+}
+
+impl Chunk {
+    pub fn new(chunk_type: ChunkType, data: impl Into<Bytes>) -> Chunk {
+        let data = data.into();
+        let length = data.len() as u32;
+
+        let bytes = &chunk_type
+            .to_string()
+            .as_bytes()
+            .iter()
+            .chain(data.iter())
+            .copied()
+            .collect::<Vec<u8>>();
+        let crc = CRC_32.checksum(bytes);
+
+        Chunk {
+            chunk_type,
+            data,
+            length,
+            crc,
+        }
+    }
+
+    pub fn length(&self) -> u32 {
+        self.data.len() as u32
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn data_as_string(&self) -> Result<String> {
+        match std::str::from_utf8(&self.data) {
+            Ok(string) => Ok(string.to_owned()),
+            Err(_) => Err("Could not convert Data to String".into()),
+        }
+    }
+
+    pub fn chunk_type(&self) -> &ChunkType {
+        &self.chunk_type
+    }
+
+    /// Puts this chunk's wire representation directly into `buf`, without an
+    /// intermediate `Vec` allocation.
+    pub fn write_to<B: BufMut>(&self, buf: &mut B) {
+        buf.put_u32(self.length);
+        buf.put_slice(&self.chunk_type.bytes());
+        buf.put_slice(&self.data);
+        buf.put_u32(self.crc);
+    }
+
+    pub fn as_bytes(&self) -> Vec<u8> {
+        let mut buf = BytesMut::with_capacity(4 + 4 + self.data.len() + 4);
+        self.write_to(&mut buf);
+        buf.to_vec()
+    }
+
+    pub fn crc(&self) -> u32 {
+        self.crc
+    }
+
+    /// Encodes `message` through `codec` before storing it as chunk data, so
+    /// callers don't have to remember to pre-encode every payload by hand.
+    pub fn new_with_payload(
+        chunk_type: ChunkType,
+        message: &[u8],
+        codec: &PayloadCodec,
+    ) -> Result<Chunk> {
+        Ok(Chunk::new(chunk_type, codec.encode(message)?))
+    }
+
+    /// Reverses `new_with_payload`'s encoding, returning the original message
+    /// bytes the chunk was created with.
+    pub fn decode_payload(&self, codec: &PayloadCodec) -> Result<Vec<u8>> {
+        codec.decode(&self.data)
+    }
+}
+
+#[derive(Debug)]
+struct ChunkTryFromLengthError;
+
+impl std::fmt::Display for ChunkTryFromLengthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Length of message does not match provided length.")
+    }
+}
+
+
+impl std::error::Error for ChunkTryFromLengthError {}
+
+#[derive(Debug)]
+struct ChunkTryFromCrcError;
+
+impl std::fmt::Display for ChunkTryFromCrcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Provided Crc is not correct.")
+    }
+}
+
+impl std::error::Error for ChunkTryFromCrcError {}
+
+#[derive(Debug)]
+struct ChunkReaderEofError {
+    context: &'static str,
+}
+
+impl std::fmt::Display for ChunkReaderEofError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unexpected end of stream while reading {}.", self.context)
+    }
+}
+
+impl std::error::Error for ChunkReaderEofError {}
+
+#[derive(Debug)]
+struct ChunkReaderLengthOverflowError {
+    length: u32,
+}
+
+impl std::fmt::Display for ChunkReaderLengthOverflowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Chunk length {} exceeds the maximum allowed PNG chunk size.",
+            self.length
+        )
+    }
+}
+
+impl std::error::Error for ChunkReaderLengthOverflowError {}
+
+impl TryFrom<&[u8]> for Chunk {
+    type Error = Box<dyn std::error::Error>;
+
+    fn try_from(bytes: &[u8]) -> std::result::Result<Self, Self::Error> {
+        let mut buffer: [u8; 4] = [0, 0, 0, 0];
+
+        let bytes_length_without_crc = bytes.len()-4;
+        let message_bytes = &bytes[8..bytes_length_without_crc];
+
+        let mut chunk_reader = BufReader::new(&bytes[4..8]);
+
+        chunk_reader.read_exact(&mut buffer)?;
+        let chunk_type = ChunkType::try_from(buffer)?;
+
+        let mut length_reader = BufReader::new(&bytes[0..4]);
+        length_reader.read_exact(&mut buffer)?;
+
+        let length = u32::from_be_bytes(buffer);
+
+        let mut crc_reader = BufReader::new(&bytes[bytes_length_without_crc..]);
+        crc_reader.read_exact(&mut buffer)?;
+
+        let crc = u32::from_be_bytes(buffer);
+
+        if length != message_bytes.len() as u32 {
+            return Err(Box::new(ChunkTryFromLengthError));
+        }
+
+        if crc != CRC_32.checksum(&bytes[4..bytes_length_without_crc]) {
+            return Err(Box::new(ChunkTryFromCrcError));
+        }
+
+        Ok(Chunk {
+            chunk_type,
+            data: Bytes::copy_from_slice(message_bytes),
+            length,
+            crc
+        })
+    }
+}
+
+impl std::fmt::Display for Chunk {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match std::str::from_utf8(&self.data) {
+            Ok(string) => write!(f, "{}", string),
+            Err(_) => Err(std::fmt::Error),
+        }
+    }
+}
+
+/// The maximum chunk length allowed by the PNG spec (2^31 - 1 bytes).
+const MAX_CHUNK_LENGTH: u32 = i32::MAX as u32;
+
+/// Pulls `Chunk`s one at a time out of any `impl Read`, so a multi-gigabyte
+/// PNG (a file, stdin, a socket) can be scanned without ever holding the
+/// whole stream in memory.
+///
+/// The CRC is checked incrementally: the type and data bytes are fed into a
+/// running `crc::Digest` as they are read instead of being re-buffered and
+/// hashed a second time, the way `Chunk::try_from(&[u8])` has to.
+pub struct ChunkReader<R: Read> {
+    reader: R,
+}
+
+impl<R: Read> ChunkReader<R> {
+    pub fn new(reader: R) -> Self {
+        ChunkReader { reader }
+    }
+
+    fn read_next(&mut self) -> Result<Option<Chunk>> {
+        let mut length_buf = [0u8; 4];
+
+        // A clean end of stream can only happen before a chunk's first byte;
+        // anything else mid-header is an unexpected EOF.
+        let first_byte_count = self.reader.read(&mut length_buf[..1])?;
+        if first_byte_count == 0 {
+            return Ok(None);
+        }
+        self.read_exact_or_eof(&mut length_buf[1..], "chunk length")?;
+        let length = u32::from_be_bytes(length_buf);
+
+        if length > MAX_CHUNK_LENGTH {
+            return Err(Box::new(ChunkReaderLengthOverflowError { length }));
+        }
+
+        let mut digest: Digest<u32> = CRC_32.digest();
+
+        let mut type_buf = [0u8; 4];
+        self.read_exact_or_eof(&mut type_buf, "chunk type")?;
+        digest.update(&type_buf);
+        let chunk_type = ChunkType::try_from(type_buf)?;
+
+        let mut data = vec![0u8; length as usize];
+        self.read_exact_or_eof(&mut data, "chunk data")?;
+        digest.update(&data);
+
+        let mut crc_buf = [0u8; 4];
+        self.read_exact_or_eof(&mut crc_buf, "chunk crc")?;
+        let crc = u32::from_be_bytes(crc_buf);
+
+        if crc != digest.finalize() {
+            return Err(Box::new(ChunkTryFromCrcError));
+        }
+
+        Ok(Some(Chunk {
+            chunk_type,
+            data: Bytes::from(data),
+            length,
+            crc,
+        }))
+    }
+
+    fn read_exact_or_eof(&mut self, buf: &mut [u8], context: &'static str) -> Result<()> {
+        self.reader.read_exact(buf).map_err(|_| {
+            Box::new(ChunkReaderEofError { context }) as Box<dyn std::error::Error>
+        })
+    }
+}
+
+impl<R: Read> Iterator for ChunkReader<R> {
+    type Item = Result<Chunk>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_next().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_type::ChunkType;
+    use std::str::FromStr;
+
+    fn testing_chunk() -> Chunk {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "This is where your secret message will be!".as_bytes();
+        let crc: u32 = 2882656334;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        Chunk::try_from(chunk_data.as_ref()).unwrap()
+    }
+
+    #[test]
+    fn test_new_chunk() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let data = "This is where your secret message will be!"
+            .as_bytes()
+            .to_vec();
+        let chunk = Chunk::new(chunk_type, data);
+        assert_eq!(chunk.length(), 42);
+        assert_eq!(chunk.crc(), 2882656334);
+    }
+
+    #[test]
+    fn test_chunk_length() {
+        let chunk = testing_chunk();
+        assert_eq!(chunk.length(), 42);
+    }
+
+    #[test]
+    fn test_chunk_type() {
+        let chunk = testing_chunk();
+        assert_eq!(chunk.chunk_type().to_string(), String::from("RuSt"));
+    }
+
+    #[test]
+    fn test_chunk_string() {
+        let chunk = testing_chunk();
+        let chunk_string = chunk.data_as_string().unwrap();
+        let expected_chunk_string = String::from("This is where your secret message will be!");
+        assert_eq!(chunk_string, expected_chunk_string);
+    }
+
+    #[test]
+    fn test_chunk_crc() {
+        let chunk = testing_chunk();
+        assert_eq!(chunk.crc(), 2882656334);
+    }
+
+    #[test]
+    fn test_valid_chunk_from_bytes() {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "This is where your secret message will be!".as_bytes();
+        let crc: u32 = 2882656334;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let chunk = Chunk::try_from(chunk_data.as_ref()).unwrap();
+
+        let chunk_string = chunk.data_as_string().unwrap();
+        let expected_chunk_string = String::from("This is where your secret message will be!");
+
+        assert_eq!(chunk.length(), 42);
+        assert_eq!(chunk.chunk_type().to_string(), String::from("RuSt"));
+        assert_eq!(chunk_string, expected_chunk_string);
+        assert_eq!(chunk.crc(), 2882656334);
+    }
+
+    #[test]
+    fn test_invalid_chunk_from_bytes() {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "This is where your secret message will be!".as_bytes();
+        let crc: u32 = 2882656333;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let chunk = Chunk::try_from(chunk_data.as_ref());
+
+        assert!(chunk.is_err());
+    }
+
+    #[test]
+    pub fn test_chunk_trait_impls() {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "This is where your secret message will be!".as_bytes();
+        let crc: u32 = 2882656334;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let chunk: Chunk = TryFrom::try_from(chunk_data.as_ref()).unwrap();
+
+        let _chunk_string = format!("{}", chunk);
+    }
+
+    #[test]
+    fn test_chunk_reader_reads_multiple_chunks() {
+        let first = Chunk::new(ChunkType::from_str("RuSt").unwrap(), "first".as_bytes().to_vec());
+        let second = Chunk::new(ChunkType::from_str("TeSt").unwrap(), "second".as_bytes().to_vec());
+
+        let mut stream = first.as_bytes();
+        stream.extend(second.as_bytes());
+
+        let chunks: Vec<Chunk> = ChunkReader::new(stream.as_slice())
+            .collect::<Result<Vec<Chunk>>>()
+            .unwrap();
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].data_as_string().unwrap(), "first");
+        assert_eq!(chunks[1].data_as_string().unwrap(), "second");
+    }
+
+    #[test]
+    fn test_chunk_reader_detects_crc_mismatch() {
+        let chunk = testing_chunk();
+        let mut bytes = chunk.as_bytes();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+
+        let result = ChunkReader::new(bytes.as_slice()).next().unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_chunk_reader_rejects_non_alphabetic_chunk_type() {
+        let chunk_type = [0xFFu8, b'A', b'A', b'A'];
+        let message_bytes = b"hello";
+
+        let mut body = Vec::new();
+        body.extend_from_slice(&chunk_type);
+        body.extend_from_slice(message_bytes);
+        let crc = CRC_32.checksum(&body);
+
+        let mut stream = Vec::new();
+        stream.extend_from_slice(&(message_bytes.len() as u32).to_be_bytes());
+        stream.extend_from_slice(&body);
+        stream.extend_from_slice(&crc.to_be_bytes());
+
+        let result = ChunkReader::new(stream.as_slice()).next().unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_chunk_try_from_rejects_non_alphabetic_chunk_type() {
+        let chunk_type = [0xFFu8, b'A', b'A', b'A'];
+        let message_bytes = b"hello";
+
+        let mut body = Vec::new();
+        body.extend_from_slice(&chunk_type);
+        body.extend_from_slice(message_bytes);
+        let crc = CRC_32.checksum(&body);
+
+        let mut chunk_data = Vec::new();
+        chunk_data.extend_from_slice(&(message_bytes.len() as u32).to_be_bytes());
+        chunk_data.extend_from_slice(&body);
+        chunk_data.extend_from_slice(&crc.to_be_bytes());
+
+        assert!(Chunk::try_from(chunk_data.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_chunk_reader_detects_eof_mid_chunk() {
+        let chunk = testing_chunk();
+        let bytes = chunk.as_bytes();
+        let truncated = &bytes[..bytes.len() - 2];
+
+        let result = ChunkReader::new(truncated).next().unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_chunk_payload_round_trips_through_base64() {
+        let codec = crate::payload::PayloadCodec::Base64;
+        let message = b"\x00binary secret\xFF";
+
+        let chunk =
+            Chunk::new_with_payload(ChunkType::from_str("RuSt").unwrap(), message, &codec).unwrap();
+        let decoded = chunk.decode_payload(&codec).unwrap();
+
+        assert_eq!(decoded, message);
+    }
+
+    #[test]
+    fn test_chunk_write_to_matches_as_bytes() {
+        let chunk = testing_chunk();
+
+        let mut buf = bytes::BytesMut::new();
+        chunk.write_to(&mut buf);
+
+        assert_eq!(buf.to_vec(), chunk.as_bytes());
+    }
+
+    #[test]
+    fn test_chunk_reader_empty_stream_yields_nothing() {
+        let mut reader = ChunkReader::new([].as_slice());
+        assert!(reader.next().is_none());
+    }
+}
@@ -26,6 +26,9 @@ impl ChunkType {
     pub fn is_critical(&self) -> bool {
         self.data[0].is_ascii_uppercase()
     }
+    pub fn is_ancillary(&self) -> bool {
+        !self.is_critical()
+    }
     pub fn is_public(&self) -> bool {
         self.data[1].is_ascii_uppercase()
     }
@@ -35,12 +38,33 @@ impl ChunkType {
     pub fn is_safe_to_copy(&self) -> bool {
         self.data[3].is_ascii_lowercase()
     }
+
+    /// Returns the closest ancillary, reserved-bit-valid equivalent of this
+    /// chunk type by lowercasing the critical bit, leaving the rest as-is.
+    pub fn suggest_safe(&self) -> ChunkType {
+        let mut data = self.data;
+        data[0] = data[0].to_ascii_lowercase();
+
+        ChunkType { data }
+    }
+
+    /// Compares this chunk type's raw bytes against a candidate type string
+    /// without going through `Display`, which errors on non-ASCII data; a
+    /// candidate of the wrong length or with non-ASCII bytes just doesn't
+    /// match, rather than panicking.
+    pub fn matches(&self, candidate: &str) -> bool {
+        candidate.as_bytes() == &self.data[..]
+    }
 }
 
 impl TryFrom<[u8; 4]> for ChunkType {
     type Error = &'static str;
 
     fn try_from(bytes: [u8; 4]) -> Result<Self, Self::Error> {
+        if !bytes.iter().all(u8::is_ascii_alphabetic) {
+            return Err("Non alphabetic character encountered.");
+        }
+
         Ok(ChunkType { data: bytes })
     }
 }
@@ -52,6 +76,10 @@ impl FromStr for ChunkType {
         let mut bytes: [u8; 4] = [0; 4];
         let string_bytes = string.as_bytes();
 
+        if string_bytes.len() != 4 {
+            return Err("Chunk type must be exactly 4 bytes long.");
+        }
+
         for index in 0..4 {
             if !string_bytes[index].is_ascii_alphabetic() {
                 return Err("Non alphabetic character encountered.")
@@ -79,6 +107,11 @@ mod tests {
     use std::convert::TryFrom;
     use std::str::FromStr;
 
+    #[test]
+    pub fn test_chunk_type_from_bytes_rejects_non_alphabetic() {
+        assert!(ChunkType::try_from([0xFF, b'A', b'A', b'A']).is_err());
+    }
+
     #[test]
     pub fn test_chunk_type_from_bytes() {
         let expected = [82, 117, 83, 116];
@@ -87,6 +120,15 @@ mod tests {
         assert_eq!(expected, actual.bytes());
     }
 
+    #[test]
+    pub fn test_chunk_type_matches() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+
+        assert!(chunk_type.matches("RuSt"));
+        assert!(!chunk_type.matches("TeSt"));
+        assert!(!chunk_type.matches("Ru"));
+    }
+
     #[test]
     pub fn test_chunk_type_from_str() {
         let expected = ChunkType::try_from([82, 117, 83, 116]).unwrap();
@@ -157,12 +199,46 @@ mod tests {
         assert!(chunk.is_err());
     }
 
+    #[test]
+    pub fn test_chunk_type_from_str_rejects_wrong_length() {
+        assert!(ChunkType::from_str("Ru").is_err());
+        assert!(ChunkType::from_str("RuStS").is_err());
+    }
+
     #[test]
     pub fn test_chunk_type_string() {
         let chunk = ChunkType::from_str("RuSt").unwrap();
         assert_eq!(&chunk.to_string(), "RuSt");
     }
 
+    #[test]
+    pub fn test_chunk_type_is_ancillary() {
+        let chunk = ChunkType::from_str("ruSt").unwrap();
+        assert!(chunk.is_ancillary());
+    }
+
+    #[test]
+    pub fn test_chunk_type_is_not_ancillary() {
+        let chunk = ChunkType::from_str("RuSt").unwrap();
+        assert!(!chunk.is_ancillary());
+    }
+
+    #[test]
+    pub fn test_chunk_type_suggest_safe_lowercases_critical_bit() {
+        let chunk = ChunkType::from_str("RuSt").unwrap();
+        let safe = chunk.suggest_safe();
+
+        assert!(safe.is_ancillary());
+        assert!(safe.is_reserved_bit_valid());
+        assert_eq!(safe.to_string(), "ruSt");
+    }
+
+    #[test]
+    pub fn test_chunk_type_suggest_safe_is_idempotent_for_ancillary_types() {
+        let chunk = ChunkType::from_str("ruSt").unwrap();
+        assert_eq!(chunk.suggest_safe(), chunk);
+    }
+
     #[test]
     pub fn test_chunk_type_trait_impls() {
         let chunk_type_1: ChunkType = TryFrom::try_from([82, 117, 83, 116]).unwrap();
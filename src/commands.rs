@@ -0,0 +1,246 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::chunk::Chunk;
+use crate::chunk_type::ChunkType;
+use crate::payload::PayloadCodec;
+use crate::png::Png;
+use crate::Result;
+
+#[derive(Debug)]
+struct UnsafeEmbedChunkTypeError {
+    chunk_type: ChunkType,
+}
+
+impl std::fmt::Display for UnsafeEmbedChunkTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Chunk type '{}' is critical and/or reserved-bit-invalid; embedding a secret message \
+             there would corrupt the image for standard decoders. Try '{}' instead.",
+            self.chunk_type,
+            self.chunk_type.suggest_safe()
+        )
+    }
+}
+
+impl std::error::Error for UnsafeEmbedChunkTypeError {}
+
+/// Rejects chunk types that would corrupt the image for standard PNG
+/// decoders if used to carry a secret message: critical chunks (the decoder
+/// would try to interpret the image with ancillary data it doesn't expect)
+/// and reserved-bit-invalid chunks (not spec-conformant in the first place).
+fn guard_embeddable_chunk_type(chunk_type: &ChunkType) -> Result<()> {
+    if chunk_type.is_ancillary() && chunk_type.is_valid() {
+        Ok(())
+    } else {
+        Err(Box::new(UnsafeEmbedChunkTypeError {
+            chunk_type: chunk_type.clone(),
+        }))
+    }
+}
+
+/// Picks the payload transform a caller asked for: a passphrase takes
+/// priority over `--base64`, since passphrase-encrypted payloads are
+/// base64-wrapped internally anyway.
+fn select_codec(base64: bool, passphrase: &Option<String>) -> PayloadCodec {
+    match passphrase {
+        Some(passphrase) => PayloadCodec::Encrypted {
+            passphrase: passphrase.clone(),
+        },
+        None if base64 => PayloadCodec::Base64,
+        None => PayloadCodec::Plain,
+    }
+}
+
+pub fn encode(
+    path: &Path,
+    chunk_type: &str,
+    message: &str,
+    output: &Option<PathBuf>,
+    base64: bool,
+    passphrase: &Option<String>,
+) -> Result<()> {
+    let mut png = Png::from_file(path)?;
+    let chunk_type = ChunkType::from_str(chunk_type)?;
+    guard_embeddable_chunk_type(&chunk_type)?;
+    let codec = select_codec(base64, passphrase);
+
+    png.append_chunk(Chunk::new_with_payload(
+        chunk_type,
+        message.as_bytes(),
+        &codec,
+    )?);
+
+    std::fs::write(output.as_deref().unwrap_or(path), png.as_bytes())?;
+
+    Ok(())
+}
+
+pub fn decode(
+    path: &Path,
+    chunk_type: &str,
+    base64: bool,
+    passphrase: &Option<String>,
+) -> Result<()> {
+    let png = Png::from_file(path)?;
+    let codec = select_codec(base64, passphrase);
+
+    match png.chunk_by_type(chunk_type) {
+        Some(chunk) => {
+            let message = chunk.decode_payload(&codec)?;
+            println!("{}", String::from_utf8(message)?);
+        }
+        None => println!("No chunk of type '{}' found.", chunk_type),
+    }
+
+    Ok(())
+}
+
+pub fn remove(path: &Path, chunk_type: &str) -> Result<()> {
+    let mut png = Png::from_file(path)?;
+    png.remove_first_chunk(chunk_type)?;
+
+    std::fs::write(path, png.as_bytes())?;
+
+    Ok(())
+}
+
+pub fn print_chunks(path: &Path) -> Result<()> {
+    let png = Png::from_file(path)?;
+
+    for chunk in png.chunks() {
+        let chunk_type = chunk.chunk_type();
+        println!(
+            "{}: {} bytes, crc={:#010x}, public={}, safe-to-copy={}",
+            chunk_type,
+            chunk.length(),
+            chunk.crc(),
+            chunk_type.is_public(),
+            chunk_type.is_safe_to_copy(),
+        );
+
+        match chunk.data_as_string() {
+            Ok(text) => println!("  data: {}", text),
+            Err(_) => println!("  data: {} bytes (not valid UTF-8)", chunk.data().len()),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::png::Png;
+
+    fn testing_png_file(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(name);
+        let png = Png::from_chunks(vec![Chunk::new(
+            ChunkType::from_str("FrSt").unwrap(),
+            "existing".as_bytes().to_vec(),
+        )]);
+        std::fs::write(&path, png.as_bytes()).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_encode_then_decode_round_trips() {
+        let path = testing_png_file("png_me_rs_test_encode_decode.png");
+
+        encode(&path, "ruSt", "hidden message", &None, false, &None).unwrap();
+
+        let png = Png::from_file(&path).unwrap();
+        let chunk = png.chunk_by_type("ruSt").unwrap();
+        assert_eq!(chunk.data_as_string().unwrap(), "hidden message");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_remove_deletes_chunk() {
+        let path = testing_png_file("png_me_rs_test_remove.png");
+
+        remove(&path, "FrSt").unwrap();
+
+        let png = Png::from_file(&path).unwrap();
+        assert!(png.chunk_by_type("FrSt").is_none());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_encode_rejects_critical_chunk_type() {
+        let path = testing_png_file("png_me_rs_test_encode_critical.png");
+
+        assert!(encode(&path, "RuSt", "hidden message", &None, false, &None).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_encode_rejects_reserved_bit_invalid_chunk_type() {
+        let path = testing_png_file("png_me_rs_test_encode_reserved.png");
+
+        assert!(encode(&path, "rust", "hidden message", &None, false, &None).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_remove_missing_chunk_errors() {
+        let path = testing_png_file("png_me_rs_test_remove_missing.png");
+
+        assert!(remove(&path, "NoNo").is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_encode_then_decode_round_trips_with_base64() {
+        let path = testing_png_file("png_me_rs_test_encode_decode_base64.png");
+
+        encode(&path, "ruSt", "hidden message", &None, true, &None).unwrap();
+
+        let png = Png::from_file(&path).unwrap();
+        let chunk = png.chunk_by_type("ruSt").unwrap();
+        assert_ne!(chunk.data_as_string().unwrap(), "hidden message");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_encode_then_decode_round_trips_with_passphrase() {
+        let path = testing_png_file("png_me_rs_test_encode_decode_encrypted.png");
+        let passphrase = Some("hunter2".to_string());
+
+        encode(&path, "ruSt", "hidden message", &None, false, &passphrase).unwrap();
+
+        let png = Png::from_file(&path).unwrap();
+        let chunk = png.chunk_by_type("ruSt").unwrap();
+        assert!(chunk.decode_payload(&select_codec(false, &passphrase)).is_ok());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_decode_with_wrong_passphrase_errors() {
+        let path = testing_png_file("png_me_rs_test_decode_wrong_passphrase.png");
+        encode(
+            &path,
+            "ruSt",
+            "hidden message",
+            &None,
+            false,
+            &Some("hunter2".to_string()),
+        )
+        .unwrap();
+
+        let png = Png::from_file(&path).unwrap();
+        let chunk = png.chunk_by_type("ruSt").unwrap();
+        let wrong = select_codec(false, &Some("wrong".to_string()));
+        assert!(chunk.decode_payload(&wrong).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -2,11 +2,39 @@ mod args;
 mod chunk_type;
 mod chunk;
 mod commands;
+mod payload;
 mod png;
 
+use args::{Cli, Commands};
+use clap::Parser;
+
 pub type Error = Box<dyn std::error::Error>;
 pub type Result<T> = std::result::Result<T, Error>;
 
 fn main() {
-    todo!();
+    let cli = Cli::parse();
+
+    let result = match &cli.command {
+        Commands::Encode {
+            path,
+            chunk_type,
+            message,
+            output,
+            base64,
+            passphrase,
+        } => commands::encode(path, chunk_type, message, output, *base64, passphrase),
+        Commands::Decode {
+            path,
+            chunk_type,
+            base64,
+            passphrase,
+        } => commands::decode(path, chunk_type, *base64, passphrase),
+        Commands::Remove { path, chunk_type } => commands::remove(path, chunk_type),
+        Commands::Print { path } => commands::print_chunks(path),
+    };
+
+    if let Err(error) = result {
+        eprintln!("Error: {}", error);
+        std::process::exit(1);
+    }
 }
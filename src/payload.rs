@@ -0,0 +1,298 @@
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use sha2::{Digest as Sha2Digest, Sha256};
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64_PAD: u8 = b'=';
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug)]
+struct Base64DecodeError;
+
+impl std::fmt::Display for Base64DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Payload is not valid base64.")
+    }
+}
+
+impl std::error::Error for Base64DecodeError {}
+
+#[derive(Debug)]
+struct PayloadTooShortError;
+
+impl std::fmt::Display for PayloadTooShortError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Encrypted payload is missing its nonce.")
+    }
+}
+
+impl std::error::Error for PayloadTooShortError {}
+
+#[derive(Debug)]
+struct EncryptionError;
+
+impl std::fmt::Display for EncryptionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Could not encrypt payload.")
+    }
+}
+
+impl std::error::Error for EncryptionError {}
+
+#[derive(Debug)]
+struct DecryptionError;
+
+impl std::fmt::Display for DecryptionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Could not decrypt payload: wrong passphrase or tampered data.")
+    }
+}
+
+impl std::error::Error for DecryptionError {}
+
+/// Mirrors the `ToBase64`/`FromBase64` pair from the old stdlib `std::rt::io::extensions`
+/// so arbitrary bytes can survive being stuffed into a chunk's `data` field.
+pub trait ToBase64 {
+    fn to_base64(&self) -> String;
+}
+
+pub trait FromBase64 {
+    // Matches the original `FromBase64` signature this mirrors; `self` here
+    // is the encoded text being decoded, not the thing being constructed.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_base64(&self) -> crate::Result<Vec<u8>>;
+}
+
+impl ToBase64 for [u8] {
+    fn to_base64(&self) -> String {
+        let mut out = String::with_capacity(self.len().div_ceil(3) * 4);
+
+        for block in self.chunks(3) {
+            let b0 = block[0];
+            let b1 = *block.get(1).unwrap_or(&0);
+            let b2 = *block.get(2).unwrap_or(&0);
+
+            let n = (b0 as u32) << 16 | (b1 as u32) << 8 | b2 as u32;
+
+            out.push(BASE64_ALPHABET[(n >> 18 & 0x3F) as usize] as char);
+            out.push(BASE64_ALPHABET[(n >> 12 & 0x3F) as usize] as char);
+            out.push(if block.len() > 1 {
+                BASE64_ALPHABET[(n >> 6 & 0x3F) as usize] as char
+            } else {
+                BASE64_PAD as char
+            });
+            out.push(if block.len() > 2 {
+                BASE64_ALPHABET[(n & 0x3F) as usize] as char
+            } else {
+                BASE64_PAD as char
+            });
+        }
+
+        out
+    }
+}
+
+impl FromBase64 for str {
+    fn from_base64(&self) -> crate::Result<Vec<u8>> {
+        let input = self.trim_end_matches(BASE64_PAD as char);
+        let mut out = Vec::with_capacity(input.len() / 4 * 3);
+        let mut buf = [0u8; 4];
+        let mut buf_len = 0;
+
+        for byte in input.bytes() {
+            let value = BASE64_ALPHABET
+                .iter()
+                .position(|&candidate| candidate == byte)
+                .ok_or(Base64DecodeError)?;
+
+            buf[buf_len] = value as u8;
+            buf_len += 1;
+
+            if buf_len == 4 {
+                let n = (buf[0] as u32) << 18
+                    | (buf[1] as u32) << 12
+                    | (buf[2] as u32) << 6
+                    | buf[3] as u32;
+                out.push((n >> 16) as u8);
+                out.push((n >> 8) as u8);
+                out.push(n as u8);
+                buf_len = 0;
+            }
+        }
+
+        if buf_len >= 2 {
+            let n = (buf[0] as u32) << 18 | (buf[1] as u32) << 12;
+            out.push((n >> 16) as u8);
+        }
+        if buf_len == 3 {
+            let n = (buf[0] as u32) << 18 | (buf[1] as u32) << 12 | (buf[2] as u32) << 6;
+            out.push((n >> 8) as u8);
+        }
+
+        Ok(out)
+    }
+}
+
+/// How a secret message is transformed before it becomes chunk `data`, and
+/// reversed again after it is read back out.
+pub enum PayloadCodec {
+    /// Store the message's raw bytes, unmodified.
+    Plain,
+    /// Base64-encode the message so arbitrary (including non-UTF-8) bytes
+    /// survive `Chunk::data_as_string`.
+    Base64,
+    /// Base64-encode an AES-256-GCM-encrypted version of the message, keyed
+    /// by a SHA-256 hash of the passphrase, with a CSPRNG-generated nonce
+    /// prepended to the ciphertext. The GCM tag authenticates both the nonce
+    /// and ciphertext, so tampering is detected rather than silently
+    /// decrypted into garbage.
+    Encrypted { passphrase: String },
+}
+
+impl PayloadCodec {
+    pub fn encode(&self, message: &[u8]) -> crate::Result<Vec<u8>> {
+        match self {
+            PayloadCodec::Plain => Ok(message.to_vec()),
+            PayloadCodec::Base64 => Ok(message.to_base64().into_bytes()),
+            PayloadCodec::Encrypted { passphrase } => {
+                let cipher = Aes256Gcm::new(&derive_key(passphrase));
+                let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+
+                let ciphertext = cipher
+                    .encrypt(&nonce, message)
+                    .map_err(|_| EncryptionError)?;
+
+                let mut payload = nonce.to_vec();
+                payload.extend(ciphertext);
+                Ok(payload.to_base64().into_bytes())
+            }
+        }
+    }
+
+    pub fn decode(&self, data: &[u8]) -> crate::Result<Vec<u8>> {
+        match self {
+            PayloadCodec::Plain => Ok(data.to_vec()),
+            PayloadCodec::Base64 => std::str::from_utf8(data)?.from_base64(),
+            PayloadCodec::Encrypted { passphrase } => {
+                let payload = std::str::from_utf8(data)?.from_base64()?;
+                if payload.len() < NONCE_LEN {
+                    return Err(Box::new(PayloadTooShortError));
+                }
+
+                let (nonce, ciphertext) = payload.split_at(NONCE_LEN);
+                let cipher = Aes256Gcm::new(&derive_key(passphrase));
+
+                cipher
+                    .decrypt(Nonce::from_slice(nonce), ciphertext)
+                    .map_err(|_| Box::new(DecryptionError) as crate::Error)
+            }
+        }
+    }
+}
+
+/// Derives a 256-bit AES key from a passphrase by hashing it with SHA-256.
+fn derive_key(passphrase: &str) -> Key<Aes256Gcm> {
+    *Key::<Aes256Gcm>::from_slice(&Sha256::digest(passphrase.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_round_trip() {
+        let message = b"This is where your secret message will be!";
+        let encoded = message.to_base64();
+        let decoded = encoded.from_base64().unwrap();
+
+        assert_eq!(decoded, message);
+    }
+
+    #[test]
+    fn test_base64_round_trip_with_padding() {
+        for message in [&b""[..], b"a", b"ab", b"abc", b"abcd"] {
+            let encoded = message.to_base64();
+            let decoded = encoded.from_base64().unwrap();
+            assert_eq!(decoded, message);
+        }
+    }
+
+    #[test]
+    fn test_plain_codec_round_trip() {
+        let message = b"raw bytes \x00\x01\xFF";
+        let codec = PayloadCodec::Plain;
+        let encoded = codec.encode(message).unwrap();
+        let decoded = codec.decode(&encoded).unwrap();
+
+        assert_eq!(decoded, message);
+    }
+
+    #[test]
+    fn test_base64_codec_round_trip_with_binary_data() {
+        let message = b"\x00\x01\x02binary\xFF";
+        let codec = PayloadCodec::Base64;
+        let encoded = codec.encode(message).unwrap();
+        let decoded = codec.decode(&encoded).unwrap();
+
+        assert_eq!(decoded, message);
+    }
+
+    #[test]
+    fn test_encrypted_codec_round_trip() {
+        let message = b"This is where your secret message will be!";
+        let codec = PayloadCodec::Encrypted {
+            passphrase: "hunter2".to_string(),
+        };
+
+        let encoded = codec.encode(message).unwrap();
+        let decoded = codec.decode(&encoded).unwrap();
+
+        assert_eq!(decoded, message);
+    }
+
+    #[test]
+    fn test_encrypted_codec_wrong_passphrase_fails_to_decrypt() {
+        let message = b"This is where your secret message will be!";
+        let encoded = PayloadCodec::Encrypted {
+            passphrase: "hunter2".to_string(),
+        }
+        .encode(message)
+        .unwrap();
+
+        let result = PayloadCodec::Encrypted {
+            passphrase: "wrong".to_string(),
+        }
+        .decode(&encoded);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_encrypted_codec_detects_tampered_ciphertext() {
+        let message = b"This is where your secret message will be!";
+        let codec = PayloadCodec::Encrypted {
+            passphrase: "hunter2".to_string(),
+        };
+        let mut encoded = codec.encode(message).unwrap();
+
+        // Flip a byte inside the base64 payload, tampering with the ciphertext.
+        let tamper_index = encoded.len() / 2;
+        encoded[tamper_index] = if encoded[tamper_index] == b'A' { b'B' } else { b'A' };
+
+        assert!(codec.decode(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_encrypted_codec_uses_a_fresh_nonce_each_time() {
+        let message = b"This is where your secret message will be!";
+        let codec = PayloadCodec::Encrypted {
+            passphrase: "hunter2".to_string(),
+        };
+
+        let first = codec.encode(message).unwrap();
+        let second = codec.encode(message).unwrap();
+
+        assert_ne!(first, second);
+    }
+}
@@ -0,0 +1,198 @@
+use std::fs;
+use std::path::Path;
+
+use crate::chunk::{Chunk, ChunkReader};
+use crate::Result;
+
+/// The 8-byte signature every PNG stream starts with.
+pub const STANDARD_HEADER: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+#[derive(Debug)]
+struct PngSignatureError;
+
+impl std::fmt::Display for PngSignatureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "File does not start with the PNG signature.")
+    }
+}
+
+impl std::error::Error for PngSignatureError {}
+
+#[derive(Debug)]
+struct ChunkNotFoundError {
+    chunk_type: String,
+}
+
+impl std::fmt::Display for ChunkNotFoundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "No chunk of type '{}' was found.", self.chunk_type)
+    }
+}
+
+impl std::error::Error for ChunkNotFoundError {}
+
+/// A PNG file: the fixed signature followed by an ordered list of chunks.
+pub struct Png {
+    chunks: Vec<Chunk>,
+}
+
+impl Png {
+    pub fn from_chunks(chunks: Vec<Chunk>) -> Png {
+        Png { chunks }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Png> {
+        if bytes.len() < STANDARD_HEADER.len() || bytes[..STANDARD_HEADER.len()] != STANDARD_HEADER {
+            return Err(Box::new(PngSignatureError));
+        }
+
+        let remaining = &bytes[STANDARD_HEADER.len()..];
+        let chunks = ChunkReader::new(remaining).collect::<Result<Vec<Chunk>>>()?;
+
+        Ok(Png::from_chunks(chunks))
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Png> {
+        Png::from_bytes(&fs::read(path)?)
+    }
+
+    pub fn append_chunk(&mut self, chunk: Chunk) {
+        self.chunks.push(chunk);
+    }
+
+    pub fn remove_first_chunk(&mut self, chunk_type: &str) -> Result<Chunk> {
+        let index = self
+            .chunks
+            .iter()
+            .position(|chunk| chunk.chunk_type().matches(chunk_type))
+            .ok_or_else(|| ChunkNotFoundError {
+                chunk_type: chunk_type.to_string(),
+            })?;
+
+        Ok(self.chunks.remove(index))
+    }
+
+    pub fn chunk_by_type(&self, chunk_type: &str) -> Option<&Chunk> {
+        self.chunks
+            .iter()
+            .find(|chunk| chunk.chunk_type().matches(chunk_type))
+    }
+
+    pub fn chunks(&self) -> &[Chunk] {
+        &self.chunks
+    }
+
+    pub fn as_bytes(&self) -> Vec<u8> {
+        STANDARD_HEADER
+            .iter()
+            .copied()
+            .chain(self.chunks.iter().flat_map(|chunk| chunk.as_bytes()))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Png {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for chunk in &self.chunks {
+            writeln!(f, "{} ({} bytes)", chunk.chunk_type(), chunk.length())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_type::ChunkType;
+    use std::str::FromStr;
+
+    fn testing_chunks() -> Vec<Chunk> {
+        vec![
+            Chunk::new(
+                ChunkType::from_str("FrSt").unwrap(),
+                "I am the first chunk".as_bytes().to_vec(),
+            ),
+            Chunk::new(
+                ChunkType::from_str("miDd").unwrap(),
+                "I am another chunk".as_bytes().to_vec(),
+            ),
+            Chunk::new(
+                ChunkType::from_str("LaSt").unwrap(),
+                "I am the last chunk".as_bytes().to_vec(),
+            ),
+        ]
+    }
+
+    fn testing_png() -> Png {
+        Png::from_chunks(testing_chunks())
+    }
+
+    #[test]
+    fn test_png_from_chunks() {
+        let png = testing_png();
+        assert_eq!(png.chunks().len(), 3);
+    }
+
+    #[test]
+    fn test_png_from_bytes_round_trip() {
+        let png = testing_png();
+        let bytes = png.as_bytes();
+
+        let round_tripped = Png::from_bytes(&bytes).unwrap();
+        assert_eq!(round_tripped.chunks().len(), 3);
+        assert_eq!(round_tripped.as_bytes(), bytes);
+    }
+
+    #[test]
+    fn test_png_from_bytes_rejects_bad_signature() {
+        let bytes = [0, 1, 2, 3, 4, 5, 6, 7];
+        assert!(Png::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_png_append_chunk() {
+        let mut png = testing_png();
+        png.append_chunk(Chunk::new(
+            ChunkType::from_str("TeSt").unwrap(),
+            "appended".as_bytes().to_vec(),
+        ));
+
+        assert_eq!(png.chunks().len(), 4);
+        assert!(png.chunk_by_type("TeSt").is_some());
+    }
+
+    #[test]
+    fn test_png_remove_first_chunk() {
+        let mut png = testing_png();
+        let removed = png.remove_first_chunk("miDd").unwrap();
+
+        assert_eq!(removed.chunk_type().to_string(), "miDd");
+        assert_eq!(png.chunks().len(), 2);
+        assert!(png.chunk_by_type("miDd").is_none());
+    }
+
+    #[test]
+    fn test_png_remove_first_chunk_not_found() {
+        let mut png = testing_png();
+        assert!(png.remove_first_chunk("NoNo").is_err());
+    }
+
+    #[test]
+    fn test_png_chunk_by_type() {
+        let png = testing_png();
+        let chunk = png.chunk_by_type("FrSt").unwrap();
+        assert_eq!(chunk.data_as_string().unwrap(), "I am the first chunk");
+    }
+
+    #[test]
+    fn test_png_chunk_by_type_does_not_panic_on_mismatched_length_query() {
+        let png = testing_png();
+        assert!(png.chunk_by_type("NotFour").is_none());
+    }
+
+    #[test]
+    fn test_png_as_bytes_starts_with_signature() {
+        let png = testing_png();
+        assert_eq!(&png.as_bytes()[..8], &STANDARD_HEADER);
+    }
+}